@@ -0,0 +1,63 @@
+//! Analyst consensus data: ratings, price targets and EPS estimates.
+//!
+//! Screening tools typically need analyst sentiment alongside raw price
+//! data. This module adds the data objects for that sentiment and the
+//! [FetchAnalysis] request trait that a
+//! [Provider](crate::provider::Provider) implements to retrieve it for a
+//! [Company].
+
+use crate::provider::{Provider, RequestResult};
+use crate::Company;
+
+/// An analyst's recommendation for an instrument.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Rating {
+    /// Strong buy.
+    StrongBuy,
+    /// Buy.
+    Buy,
+    /// Hold.
+    Hold,
+    /// Sell.
+    Sell,
+    /// Strong sell.
+    StrongSell,
+}
+
+/// The consensus price target for an instrument.
+#[derive(Debug, Clone, PartialEq)]
+pub struct PriceTarget {
+    /// The lowest individual analyst target.
+    pub low: f64,
+    /// The mean analyst target.
+    pub mean: f64,
+    /// The highest individual analyst target.
+    pub high: f64,
+    /// The ISO 4217 currency code the target is denominated in.
+    pub currency: String,
+}
+
+/// The consensus earnings-per-share estimate for a reporting period.
+#[derive(Debug, Clone, PartialEq)]
+pub struct EpsConsensus {
+    /// The reporting period this estimate covers, e.g. `"2026Q2"`.
+    pub period: String,
+    /// The consensus EPS estimate.
+    pub estimate: f64,
+    /// The actual reported EPS, once available.
+    pub actual: Option<f64>,
+    /// The number of analysts contributing to the estimate.
+    pub num_analysts: u32,
+}
+
+/// A [Provider] capable of fetching analyst consensus data for a [Company].
+pub trait FetchAnalysis: Provider {
+    /// Get the individual analyst [Rating]s for `c`.
+    fn ratings(&self, c: &dyn Company) -> RequestResult<Vec<Rating>>;
+
+    /// Get the consensus [PriceTarget] for `c`.
+    fn price_target(&self, c: &dyn Company) -> RequestResult<PriceTarget>;
+
+    /// Get the [EpsConsensus] history/estimates for `c`.
+    fn eps_consensus(&self, c: &dyn Company) -> RequestResult<Vec<EpsConsensus>>;
+}
@@ -0,0 +1,33 @@
+//! Asset-class classification for instruments.
+//!
+//! [Company](crate::Company) originally assumed every instrument was an
+//! equity. [AssetClass] lets the same trait describe bonds, crypto pairs,
+//! derivatives and other instrument kinds without forking the trait per
+//! asset type.
+
+/// The broad kind of instrument a [Company](crate::Company) describes.
+///
+/// # Description
+///
+/// Most fields on [Company](crate::Company) (name, ticker, ISIN...) make
+/// sense across asset classes, but some behaviour (price/quantity precision,
+/// whether a maturity applies, ...) depends on which of these an instrument
+/// is. `AssetClass` lets a `Market`/`Company` implementation describe a
+/// _NASDAQ100_ stock and a `btc/usd` spot pair behind the same interface.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum AssetClass {
+    /// A listed equity, e.g. a share of common stock.
+    Stock,
+    /// A fixed-income instrument, e.g. a government or corporate bond.
+    Bond,
+    /// A cryptocurrency or crypto trading pair.
+    Crypto,
+    /// A fiat currency.
+    Fiat,
+    /// A physical or financial commodity.
+    Commodity,
+    /// An options contract.
+    Option,
+    /// A futures contract.
+    Future,
+}
@@ -0,0 +1,61 @@
+//! Sector/industry classification schemes.
+//!
+//! [Company](crate::Company) originally had no notion of sector or industry.
+//! This module adds a generic [Code]/[ClassificationScheme] pair so markets
+//! can group and filter holdings by any hierarchical scheme -- GICS, ICB, or
+//! a provider-specific taxonomy -- without the crate committing to one.
+
+use std::marker::PhantomData;
+
+/// A code within a classification scheme `T`, forming a hierarchy through
+/// an optional parent code.
+///
+/// # Description
+///
+/// `T` is a zero-sized marker type identifying which scheme this code
+/// belongs to (see [Gics]), so `Code<Gics>` and a hypothetical `Code<Icb>`
+/// are distinct types even though they share the same shape.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Code<T> {
+    /// The code string, e.g. `"45"` for GICS Information Technology.
+    pub code: String,
+    /// The human-readable description, e.g. `"Information Technology"`.
+    pub description: String,
+    /// The code string of the parent in the hierarchy, when any.
+    pub parent: Option<String>,
+    _scheme: PhantomData<T>,
+}
+
+impl<T> Code<T> {
+    /// Build a new `Code`.
+    pub fn new(code: impl Into<String>, description: impl Into<String>, parent: Option<String>) -> Self {
+        Self {
+            code: code.into(),
+            description: description.into(),
+            parent,
+            _scheme: PhantomData,
+        }
+    }
+}
+
+/// A registry of [Code]s forming a classification scheme `T`.
+///
+/// # Description
+///
+/// Implementors hold the full set of codes for a scheme (e.g. all GICS
+/// sectors, industry groups, industries and sub-industries) and expose
+/// lookup and hierarchy traversal over them.
+pub trait ClassificationScheme<T> {
+    /// Get the [Code] whose `code` field is equal to `code`, if any.
+    fn lookup(&self, code: &str) -> Option<&Code<T>>;
+
+    /// Get the direct children of the code identified by `code`.
+    fn children(&self, code: &str) -> Vec<&Code<T>>;
+
+    /// Get the top-level codes of this scheme, i.e. those without a parent.
+    fn roots(&self) -> Vec<&Code<T>>;
+}
+
+/// Marker type for the Global Industry Classification Standard.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Gics;
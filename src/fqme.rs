@@ -0,0 +1,193 @@
+//! Fully-qualified market endpoint (FQME) addressing.
+//!
+//! An FQME string addresses an instrument uniformly across venues and
+//! providers, independently of which [Market](crate::Market)/
+//! [Company](crate::Company) implementation resolved it. It has the shape:
+//!
+//! ```text
+//! dst[/src].venue.expiry.broker
+//! ```
+//!
+//! where `dst` is the traded asset, the optional `src` is the settlement or
+//! quote asset, `venue` is the exchange, and `broker` is the backend/provider
+//! system id. For example `btc/usd.spot.kraken` or `aapl.nasdaq.ibkr`.
+
+use std::fmt;
+
+/// An error produced while parsing an FQME string.
+#[derive(Debug, Clone, PartialEq)]
+pub enum ParseError {
+    /// The string was empty.
+    Empty,
+    /// The string did not contain enough `.`-separated segments.
+    MissingSegments,
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ParseError::Empty => write!(f, "FQME string is empty"),
+            ParseError::MissingSegments => write!(f, "FQME string is missing required segments"),
+        }
+    }
+}
+
+impl std::error::Error for ParseError {}
+
+/// The components of an FQME string, as produced by [unpack_fqme].
+///
+/// # Description
+///
+/// `expiry` and `src` are filled with an empty string when the original FQME
+/// did not specify them, e.g. `aapl.nasdaq.ibkr` has no `src` segment since
+/// equities settle in the market's own currency.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FqmeParts {
+    /// The traded asset, e.g. `btc` or `aapl`.
+    pub dst: String,
+    /// The settlement/quote asset, e.g. `usd`. Empty when not specified.
+    pub src: String,
+    /// The exchange, e.g. `spot` or `nasdaq`.
+    pub venue: String,
+    /// The contract expiry, e.g. a futures expiry code. Empty when not applicable.
+    pub expiry: String,
+    /// The backend/provider system id, e.g. `kraken` or `ibkr`.
+    pub broker: String,
+    /// Whether `dst` was resolved to a real [Company](crate::Company) rather
+    /// than kept as a bare string.
+    pub resolved: bool,
+}
+
+/// A fully-qualified market endpoint, uniquely addressing an instrument.
+///
+/// # Description
+///
+/// `Fqme` is the parsed, strongly-typed counterpart of an FQME string. It is
+/// also referred to as `MktPair` when only the `dst`/`src` pair matters, e.g.
+/// when comparing the traded asset against its settlement asset regardless of
+/// venue or broker.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Fqme {
+    parts: FqmeParts,
+}
+
+/// An alias of [Fqme], emphasising the `dst`/`src` trading pair it encodes.
+pub type MktPair = Fqme;
+
+impl Fqme {
+    /// Build an `Fqme` from already-parsed [FqmeParts].
+    pub fn new(parts: FqmeParts) -> Self {
+        Self { parts }
+    }
+
+    /// Parse `s` as an FQME string.
+    pub fn parse(s: &str) -> Result<Self, ParseError> {
+        Ok(Self::new(unpack_fqme(s)?))
+    }
+
+    /// Get the parsed components of this FQME.
+    pub fn parts(&self) -> &FqmeParts {
+        &self.parts
+    }
+}
+
+impl fmt::Display for Fqme {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let p = &self.parts;
+        if p.src.is_empty() {
+            write!(f, "{}.{}.{}.{}", p.dst, p.venue, p.expiry, p.broker)
+        } else {
+            write!(f, "{}/{}.{}.{}.{}", p.dst, p.src, p.venue, p.expiry, p.broker)
+        }
+    }
+}
+
+/// Split an FQME string into its [FqmeParts].
+///
+/// # Description
+///
+/// Splits `s` on `.` to obtain `dst[/src]`, `venue`, `expiry` and `broker`,
+/// then splits the first segment on `/` to separate `dst` from the optional
+/// `src`. Missing `src`/`expiry` segments are tolerated and filled as empty
+/// strings; `resolved` is always `false` since this free function has no
+/// access to a [Market](crate::Market) to resolve `dst` against.
+///
+/// ## Returns
+///
+/// [ParseError::Empty] when `s` is empty, [ParseError::MissingSegments] when
+/// fewer than the `dst[/src].venue.broker` segments are present.
+pub fn unpack_fqme(s: &str) -> Result<FqmeParts, ParseError> {
+    if s.is_empty() {
+        return Err(ParseError::Empty);
+    }
+
+    let mut segments = s.split('.');
+    let dst_src = segments.next().ok_or(ParseError::MissingSegments)?;
+    let venue = segments.next().ok_or(ParseError::MissingSegments)?;
+    let rest: Vec<&str> = segments.collect();
+
+    let (expiry, broker) = match rest.len() {
+        0 => return Err(ParseError::MissingSegments),
+        1 => (String::new(), rest[0].to_string()),
+        _ => (rest[0].to_string(), rest[1..].join(".")),
+    };
+
+    let mut dst_src_iter = dst_src.splitn(2, '/');
+    let dst = dst_src_iter.next().unwrap_or_default().to_string();
+    let src = dst_src_iter.next().unwrap_or_default().to_string();
+
+    Ok(FqmeParts {
+        dst,
+        src,
+        venue: venue.to_string(),
+        expiry,
+        broker,
+        resolved: false,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_a_pair_with_settlement_asset() {
+        let parts = unpack_fqme("btc/usd.spot.kraken").unwrap();
+        assert_eq!(parts.dst, "btc");
+        assert_eq!(parts.src, "usd");
+        assert_eq!(parts.venue, "spot");
+        assert_eq!(parts.expiry, "");
+        assert_eq!(parts.broker, "kraken");
+        assert!(!parts.resolved);
+    }
+
+    #[test]
+    fn parses_an_equity_without_settlement_asset() {
+        let parts = unpack_fqme("aapl.nasdaq.ibkr").unwrap();
+        assert_eq!(parts.dst, "aapl");
+        assert_eq!(parts.src, "");
+        assert_eq!(parts.venue, "nasdaq");
+        assert_eq!(parts.expiry, "");
+        assert_eq!(parts.broker, "ibkr");
+    }
+
+    #[test]
+    fn parses_an_expiry_segment_when_present() {
+        let parts = unpack_fqme("es.cme.dec26.ibkr").unwrap();
+        assert_eq!(parts.dst, "es");
+        assert_eq!(parts.venue, "cme");
+        assert_eq!(parts.expiry, "dec26");
+        assert_eq!(parts.broker, "ibkr");
+    }
+
+    #[test]
+    fn rejects_an_empty_string() {
+        assert_eq!(unpack_fqme(""), Err(ParseError::Empty));
+    }
+
+    #[test]
+    fn rejects_a_string_missing_segments() {
+        assert_eq!(unpack_fqme("aapl.nasdaq"), Err(ParseError::MissingSegments));
+        assert_eq!(unpack_fqme("aapl"), Err(ParseError::MissingSegments));
+    }
+}
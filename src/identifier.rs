@@ -0,0 +1,154 @@
+//! Cross-symbology instrument identifiers.
+//!
+//! [Company](crate::Company) originally exposed only `isin`, `ticker` and a
+//! free-form `extra_id`. This module adds a structured [Identifier] enum so
+//! the same instrument can carry its Bloomberg FIGI, national codes, and the
+//! ISO 10383 MIC of its listing venue -- the cross-symbology mapping needed
+//! when one security trades under different tickers on different exchanges
+//! (e.g. Apple as `AAPL` vs `APC`).
+
+use std::fmt;
+
+/// An error produced while validating an [Identifier].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum IdError {
+    /// The identifier has the wrong length for its kind.
+    InvalidLength,
+    /// The identifier contains a character that is not valid for its kind.
+    InvalidCharacter,
+    /// The identifier's check digit does not match the computed one.
+    CheckDigitMismatch,
+}
+
+impl fmt::Display for IdError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            IdError::InvalidLength => write!(f, "identifier has an invalid length"),
+            IdError::InvalidCharacter => write!(f, "identifier contains an invalid character"),
+            IdError::CheckDigitMismatch => write!(f, "identifier check digit does not match"),
+        }
+    }
+}
+
+impl std::error::Error for IdError {}
+
+/// A structured identifier carried by a [Company](crate::Company).
+///
+/// # Description
+///
+/// Unlike the free-form `extra_id`, each variant carries the raw code for a
+/// specific, well-known symbology, so consumers can look up exactly the
+/// identifier they need (e.g. the FIGI) without parsing a loosely-typed
+/// string.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Identifier {
+    /// International Securities Identification Number.
+    Isin(String),
+    /// Bloomberg Financial Instrument Global Identifier.
+    Figi(String),
+    /// Bloomberg Composite Financial Instrument Global Identifier.
+    CompositeFigi(String),
+    /// CUSIP, the North-American securities identifier.
+    Cusip(String),
+    /// SEDOL, the UK/Ireland securities identifier.
+    Sedol(String),
+    /// ISO 10383 Market Identifier Code of the listing venue.
+    MicExchangeCode(String),
+    /// Any other, provider-specific identifier, named by its first field.
+    Custom(String, String),
+}
+
+impl Identifier {
+    /// Validate this identifier's check digit, when its kind defines one.
+    ///
+    /// # Description
+    ///
+    /// Only [Identifier::Isin] currently has a defined check-digit algorithm;
+    /// every other variant returns `Ok(())` since this crate does not define
+    /// a check-digit algorithm for it.
+    pub fn validate(&self) -> Result<(), IdError> {
+        match self {
+            Identifier::Isin(code) => validate_isin(code),
+            _ => Ok(()),
+        }
+    }
+}
+
+/// Validate the check digit of an ISIN.
+///
+/// # Description
+///
+/// Takes the first 11 characters of `isin`, converts each letter to its
+/// ordinal (`A` = 10 ... `Z` = 35) expanded to its constituent digits,
+/// concatenates all the digits, then applies the mod-10 Luhn
+/// "double-add-double" from the rightmost digit. The check digit is
+/// `(10 - (sum mod 10)) mod 10` and must equal the 12th character of `isin`.
+fn validate_isin(isin: &str) -> Result<(), IdError> {
+    let chars: Vec<char> = isin.chars().collect();
+    if chars.len() != 12 {
+        return Err(IdError::InvalidLength);
+    }
+    if !chars.iter().all(|c| c.is_ascii_alphanumeric()) {
+        return Err(IdError::InvalidCharacter);
+    }
+
+    let check_digit = chars[11].to_digit(10).ok_or(IdError::InvalidCharacter)?;
+
+    let mut digits = Vec::with_capacity(24);
+    for c in &chars[..11] {
+        if c.is_ascii_digit() {
+            digits.push(c.to_digit(10).ok_or(IdError::InvalidCharacter)?);
+        } else {
+            let value = c.to_ascii_uppercase() as u32 - 'A' as u32 + 10;
+            digits.push(value / 10);
+            digits.push(value % 10);
+        }
+    }
+
+    let mut sum = 0u32;
+    for (i, digit) in digits.iter().rev().enumerate() {
+        let value = if i % 2 == 0 { digit * 2 } else { *digit };
+        sum += value / 10 + value % 10;
+    }
+
+    let computed = (10 - (sum % 10)) % 10;
+    if computed == check_digit {
+        Ok(())
+    } else {
+        Err(IdError::CheckDigitMismatch)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn validates_known_good_isins() {
+        // Apple Inc., BAE Systems and SAP SE.
+        for isin in ["US0378331005", "GB0002634946", "DE0007164600"] {
+            assert_eq!(Identifier::Isin(isin.to_string()).validate(), Ok(()));
+        }
+    }
+
+    #[test]
+    fn rejects_a_mismatched_check_digit() {
+        assert_eq!(
+            Identifier::Isin("US0378331006".to_string()).validate(),
+            Err(IdError::CheckDigitMismatch)
+        );
+    }
+
+    #[test]
+    fn rejects_the_wrong_length() {
+        assert_eq!(
+            Identifier::Isin("US037833100".to_string()).validate(),
+            Err(IdError::InvalidLength)
+        );
+    }
+
+    #[test]
+    fn non_isin_identifiers_always_validate() {
+        assert_eq!(Identifier::Figi("BBG000B9XRY4".to_string()).validate(), Ok(()));
+    }
+}
@@ -11,6 +11,20 @@
 
 use std::fmt;
 
+pub use asset_class::AssetClass;
+
+pub mod analysis;
+pub mod asset_class;
+pub mod classification;
+pub mod fqme;
+pub mod identifier;
+pub mod portfolio;
+pub mod provider;
+pub mod quote;
+
+use classification::{Code, Gics};
+use identifier::Identifier;
+
 /// A stock market description.
 ///
 /// The [Market] trait provides an abstract definition of the functionality that is
@@ -88,6 +102,28 @@ pub trait Market {
     /// This method builds a vector with references to all the stock descriptors (
     /// objects that implement the [Company] trait) that are included in the market.
     fn get_companies(&self) -> Vec<&Box<dyn Company>>;
+
+    /// Get a list of the stock descriptors of a given [AssetClass] included in the market.
+    ///
+    /// # Description
+    ///
+    /// Default implementation built on top of [Market::get_companies], filtering
+    /// by [Company::asset_class]. Override it when a backend can filter more
+    /// efficiently, e.g. when stocks and derivatives are stored separately.
+    fn get_companies_by_class(&self, class: AssetClass) -> Vec<&Box<dyn Company>> {
+        self.get_companies()
+            .into_iter()
+            .filter(|c| c.asset_class() == class)
+            .collect()
+    }
+
+    /// Get a list of the ticker identifiers of a given [AssetClass] included in the market.
+    fn list_tickers_by_class(&self, class: AssetClass) -> Vec<&str> {
+        self.get_companies_by_class(class)
+            .into_iter()
+            .map(|c| c.ticker())
+            .collect()
+    }
 }
 
 /// A company description.
@@ -139,6 +175,71 @@ pub trait Company {
     ///
     /// `None` when no special ID is linked to the stock. An ID otherwise.
     fn extra_id(&self) -> Option<&String>;
+
+    /// Get the [AssetClass] of this instrument.
+    fn asset_class(&self) -> AssetClass;
+
+    /// Get the number of decimal digits used to express the minimum price
+    /// increment (tick size) of this instrument.
+    ///
+    /// # Description
+    ///
+    /// Mostly relevant for non-equity instruments such as futures or crypto
+    /// pairs, where the price precision is not implied by the currency alone.
+    ///
+    /// ## Returns
+    ///
+    /// `None` when this instrument does not define an explicit tick size.
+    fn tick_size_digits(&self) -> Option<u8> {
+        None
+    }
+
+    /// Get the number of decimal digits used to express the minimum tradable
+    /// quantity (lot size) of this instrument.
+    ///
+    /// ## Returns
+    ///
+    /// `None` when this instrument does not define an explicit lot size.
+    fn lot_size_digits(&self) -> Option<u8> {
+        None
+    }
+
+    /// Get the fully-qualified market endpoint (FQME) string that addresses
+    /// this instrument across venues and providers, e.g. `aapl.nasdaq.ibkr`.
+    ///
+    /// # Description
+    ///
+    /// See the [fqme] module for the `dst[/src].venue.expiry.broker` format
+    /// and the [fqme::unpack_fqme] parser that decomposes it again.
+    fn fqme(&self) -> String;
+
+    /// Get the structured identifiers carried by this instrument.
+    ///
+    /// # Description
+    ///
+    /// Complements `isin`/`ticker`/`extra_id` with the cross-symbology
+    /// mapping (FIGI, CUSIP, SEDOL, listing MIC, ...) needed when the same
+    /// instrument trades under different tickers on different exchanges. See
+    /// the [identifier] module for the [Identifier] variants and ISIN
+    /// check-digit validation.
+    fn identifiers(&self) -> &[Identifier];
+
+    /// Get the GICS sector this instrument is classified under, if any.
+    ///
+    /// # Description
+    ///
+    /// Returns `None` for instruments that do not carry a sector
+    /// classification, e.g. currencies or commodities. See the
+    /// [classification] module for the generic [Code]/[ClassificationScheme]
+    /// machinery this builds on.
+    ///
+    /// [ClassificationScheme]: classification::ClassificationScheme
+    fn sector(&self) -> Option<Code<Gics>> {
+        None
+    }
+
+    /// Get the ISO 4217 currency code this instrument is denominated/traded in.
+    fn currency(&self) -> &str;
 }
 
 impl fmt::Display for dyn Company {
@@ -0,0 +1,481 @@
+//! Position and portfolio accounting.
+//!
+//! Builds on [Company]'s instrument metadata (ISIN, currency, tick/lot size)
+//! to turn this otherwise metadata-only crate into one usable for pre-trade
+//! pricing and P&L: [Transaction]s accumulate into [Position]s, and
+//! positions accumulate into a [Portfolio].
+
+use std::collections::HashMap;
+
+use crate::provider::{RequestError, RequestResult};
+use crate::quote::FetchQuote;
+use crate::Company;
+
+/// An amount of money denominated in an ISO 4217 currency.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Money {
+    /// The amount.
+    pub amount: f64,
+    /// The ISO 4217 currency code `amount` is denominated in.
+    pub currency: String,
+}
+
+impl Money {
+    /// Build a new `Money` value.
+    pub fn new(amount: f64, currency: impl Into<String>) -> Self {
+        Self {
+            amount,
+            currency: currency.into(),
+        }
+    }
+}
+
+/// Which side of the market a [Transaction] was executed on.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Side {
+    /// The transaction bought the instrument.
+    Buy,
+    /// The transaction sold the instrument.
+    Sell,
+}
+
+/// A single executed trade in an instrument.
+#[derive(Debug)]
+pub struct Transaction {
+    /// The traded instrument.
+    pub instrument: Box<dyn Company>,
+    /// The traded quantity, always positive; [Transaction::side] gives the direction.
+    pub qty: f64,
+    /// The execution price, in the instrument's [Company::currency].
+    pub price: f64,
+    /// The UTC timestamp of execution, in RFC 3339 format.
+    pub timestamp: String,
+    /// Whether this transaction bought or sold the instrument.
+    pub side: Side,
+}
+
+/// An open position in an instrument, accumulated from [Transaction]s.
+#[derive(Debug)]
+pub struct Position {
+    /// The held instrument.
+    pub instrument: Box<dyn Company>,
+    /// The held quantity, positive for a long position, negative for a short one.
+    pub quantity: f64,
+    /// The volume-weighted average cost of the open quantity.
+    pub avg_cost: f64,
+}
+
+impl Position {
+    /// Round `amount` to the instrument's lot-size precision, defaulting to
+    /// 2 decimal digits when [Company::lot_size_digits] is unset.
+    fn round_amount(&self, amount: f64) -> f64 {
+        let digits = self.instrument.lot_size_digits().unwrap_or(2) as i32;
+        let factor = 10f64.powi(digits);
+        (amount * factor).round() / factor
+    }
+}
+
+/// A collection of [Position]s, with the accumulated realized P&L.
+///
+/// # Description
+///
+/// `Portfolio` replays [Transaction]s through [Portfolio::apply]: opening or
+/// adding to a position updates its volume-weighted average cost, while
+/// reducing or closing one realizes P&L against that average cost.
+#[derive(Debug, Default)]
+pub struct Portfolio {
+    positions: Vec<Position>,
+    realized_pnl: HashMap<String, f64>,
+}
+
+impl Portfolio {
+    /// Build an empty `Portfolio`.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Get the currently open [Position]s.
+    ///
+    /// # Description
+    ///
+    /// A position that [Portfolio::apply] has closed down to a `quantity` of
+    /// zero is dropped rather than kept around, so every entry here is truly
+    /// open.
+    pub fn positions(&self) -> &[Position] {
+        &self.positions
+    }
+
+    /// Apply `tx` to this portfolio, opening, growing, reducing or closing
+    /// the matching [Position].
+    ///
+    /// # Description
+    ///
+    /// Positions are matched by [Company::isin]. Reducing or closing a
+    /// position realizes P&L (rounded to the instrument's lot-size
+    /// precision) against the position's `avg_cost`, denominated in the
+    /// instrument's [Company::currency]. A position fully closed down to a
+    /// `quantity` of zero is removed, so later lookups (and
+    /// [Portfolio::notional]/[Portfolio::unrealized_pnl]) never see it, nor
+    /// need to fetch a quote for an instrument that is no longer held.
+    pub fn apply(&mut self, tx: Transaction) {
+        let signed_qty = match tx.side {
+            Side::Buy => tx.qty,
+            Side::Sell => -tx.qty,
+        };
+
+        if let Some(idx) = self
+            .positions
+            .iter()
+            .position(|p| p.instrument.isin() == tx.instrument.isin())
+        {
+            let pos = &mut self.positions[idx];
+            let same_direction = pos.quantity == 0.0 || (pos.quantity > 0.0) == (signed_qty > 0.0);
+            if same_direction {
+                let total_qty = pos.quantity + signed_qty;
+                pos.avg_cost = (pos.avg_cost * pos.quantity.abs() + tx.price * signed_qty.abs()) / total_qty.abs();
+                pos.quantity = total_qty;
+            } else {
+                let closed_qty = signed_qty.abs().min(pos.quantity.abs());
+                let pnl = if pos.quantity > 0.0 {
+                    (tx.price - pos.avg_cost) * closed_qty
+                } else {
+                    (pos.avg_cost - tx.price) * closed_qty
+                };
+                let pnl = pos.round_amount(pnl);
+                *self
+                    .realized_pnl
+                    .entry(pos.instrument.currency().to_string())
+                    .or_insert(0.0) += pnl;
+                pos.quantity += signed_qty;
+                if signed_qty.abs() > closed_qty {
+                    // The transaction closed the old position and opened a new
+                    // one on the opposite side; that residual leg's cost basis
+                    // is this transaction's price, not the old position's.
+                    pos.avg_cost = tx.price;
+                }
+            }
+
+            if self.positions[idx].quantity == 0.0 {
+                self.positions.remove(idx);
+            }
+        } else {
+            self.positions.push(Position {
+                instrument: tx.instrument,
+                quantity: signed_qty,
+                avg_cost: tx.price,
+            });
+        }
+    }
+
+    /// Get the realized P&L accumulated so far, as one [Money] per currency.
+    pub fn realized_pnl(&self) -> Vec<Money> {
+        self.realized_pnl
+            .iter()
+            .map(|(currency, amount)| Money::new(*amount, currency.clone()))
+            .collect()
+    }
+
+    /// Get the unrealized P&L of the currently open positions, as one
+    /// [Money] per currency.
+    ///
+    /// # Description
+    ///
+    /// Fetches the latest [Quote](crate::quote::Quote) for each position via
+    /// `quotes` and compares it against the position's `avg_cost`, bucketed
+    /// by the instrument's [Company::currency] (not the quote's), so that a
+    /// provider quoting in a different currency than the instrument's
+    /// reported one (e.g. a cross-listed ADR) does not split one
+    /// instrument's P&L across buckets.
+    pub fn unrealized_pnl(&self, quotes: &dyn FetchQuote) -> RequestResult<Vec<Money>> {
+        let mut by_currency: HashMap<String, f64> = HashMap::new();
+        for pos in &self.positions {
+            let quote = quotes.latest_quote(pos.instrument.as_ref())?;
+            let pnl = pos.round_amount((quote.price - pos.avg_cost) * pos.quantity);
+            *by_currency
+                .entry(pos.instrument.currency().to_string())
+                .or_insert(0.0) += pnl;
+        }
+        Ok(by_currency
+            .into_iter()
+            .map(|(currency, amount)| Money::new(amount, currency))
+            .collect())
+    }
+
+    /// Get the total notional value of the currently open positions.
+    ///
+    /// # Description
+    ///
+    /// Fetches the latest [Quote](crate::quote::Quote) for each position via
+    /// `quotes` and sums `quantity * price`, denominated in the instrument's
+    /// [Company::currency] (not the quote's), consistently with
+    /// [Portfolio::realized_pnl] and [Portfolio::unrealized_pnl]. All
+    /// positions must share the same instrument currency; a multi-currency
+    /// portfolio should be summed per currency by the caller instead.
+    ///
+    /// ## Returns
+    ///
+    /// [RequestError::BadResponse] when the open positions span more than
+    /// one currency.
+    pub fn notional(&self, quotes: &dyn FetchQuote) -> RequestResult<Money> {
+        let mut total = 0.0;
+        let mut currency: Option<String> = None;
+        for pos in &self.positions {
+            let quote = quotes.latest_quote(pos.instrument.as_ref())?;
+            let instrument_currency = pos.instrument.currency();
+            match &currency {
+                None => currency = Some(instrument_currency.to_string()),
+                Some(c) if c != instrument_currency => {
+                    return Err(RequestError::BadResponse(
+                        "portfolio spans more than one currency".to_string(),
+                    ))
+                }
+                _ => {}
+            }
+            total += pos.quantity * quote.price;
+        }
+        Ok(Money::new(total, currency.unwrap_or_default()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::identifier::Identifier;
+    use crate::provider::Provider;
+    use crate::quote::{DateRange, Interval, Ohlc, Quote};
+    use crate::AssetClass;
+
+    struct TestCompany {
+        isin: String,
+        ticker: String,
+        currency: String,
+    }
+
+    impl Company for TestCompany {
+        fn name(&self) -> &str {
+            &self.ticker
+        }
+        fn full_name(&self) -> Option<&String> {
+            None
+        }
+        fn isin(&self) -> &str {
+            &self.isin
+        }
+        fn ticker(&self) -> &str {
+            &self.ticker
+        }
+        fn extra_id(&self) -> Option<&String> {
+            None
+        }
+        fn asset_class(&self) -> AssetClass {
+            AssetClass::Stock
+        }
+        fn fqme(&self) -> String {
+            format!("{}.test.test", self.ticker)
+        }
+        fn identifiers(&self) -> &[Identifier] {
+            &[]
+        }
+        fn currency(&self) -> &str {
+            &self.currency
+        }
+    }
+
+    fn company_in(isin: &str, currency: &str) -> Box<dyn Company> {
+        Box::new(TestCompany {
+            isin: isin.to_string(),
+            ticker: "TST".to_string(),
+            currency: currency.to_string(),
+        })
+    }
+
+    fn buy(isin: &str, qty: f64, price: f64) -> Transaction {
+        buy_in(isin, "USD", qty, price)
+    }
+
+    fn sell(isin: &str, qty: f64, price: f64) -> Transaction {
+        sell_in(isin, "USD", qty, price)
+    }
+
+    fn buy_in(isin: &str, currency: &str, qty: f64, price: f64) -> Transaction {
+        Transaction {
+            instrument: company_in(isin, currency),
+            qty,
+            price,
+            timestamp: "2026-01-01T00:00:00Z".to_string(),
+            side: Side::Buy,
+        }
+    }
+
+    fn sell_in(isin: &str, currency: &str, qty: f64, price: f64) -> Transaction {
+        Transaction {
+            instrument: company_in(isin, currency),
+            qty,
+            price,
+            timestamp: "2026-01-01T00:00:00Z".to_string(),
+            side: Side::Sell,
+        }
+    }
+
+    /// A [FetchQuote] test double that serves canned [Quote]s keyed by ISIN.
+    struct TestQuotes {
+        quotes: HashMap<String, Quote>,
+    }
+
+    impl TestQuotes {
+        fn new(quotes: &[(&str, f64, &str)]) -> Self {
+            let quotes = quotes
+                .iter()
+                .map(|(isin, price, currency)| {
+                    (
+                        isin.to_string(),
+                        Quote {
+                            price: *price,
+                            bid: *price,
+                            ask: *price,
+                            volume: 0.0,
+                            currency: currency.to_string(),
+                            timestamp: "2026-01-01T00:00:00Z".to_string(),
+                        },
+                    )
+                })
+                .collect();
+            Self { quotes }
+        }
+    }
+
+    impl Provider for TestQuotes {
+        fn provider_name(&self) -> &str {
+            "test-quotes"
+        }
+    }
+
+    impl FetchQuote for TestQuotes {
+        fn latest_quote(&self, c: &dyn Company) -> RequestResult<Quote> {
+            self.quotes.get(c.isin()).cloned().ok_or(RequestError::NotFound)
+        }
+
+        fn history(&self, _c: &dyn Company, _interval: Interval, _range: DateRange) -> RequestResult<Vec<Ohlc>> {
+            Err(RequestError::NotSupported)
+        }
+    }
+
+    #[test]
+    fn opens_a_position() {
+        let mut p = Portfolio::new();
+        p.apply(buy("US0000000001", 10.0, 100.0));
+        assert_eq!(p.positions().len(), 1);
+        assert_eq!(p.positions()[0].quantity, 10.0);
+        assert_eq!(p.positions()[0].avg_cost, 100.0);
+    }
+
+    #[test]
+    fn adds_to_a_position_updating_avg_cost() {
+        let mut p = Portfolio::new();
+        p.apply(buy("US0000000001", 10.0, 100.0));
+        p.apply(buy("US0000000001", 10.0, 120.0));
+        assert_eq!(p.positions().len(), 1);
+        assert_eq!(p.positions()[0].quantity, 20.0);
+        assert_eq!(p.positions()[0].avg_cost, 110.0);
+    }
+
+    #[test]
+    fn reduces_a_position_realizing_pnl() {
+        let mut p = Portfolio::new();
+        p.apply(buy("US0000000001", 10.0, 100.0));
+        p.apply(sell("US0000000001", 4.0, 120.0));
+        assert_eq!(p.positions()[0].quantity, 6.0);
+        assert_eq!(p.positions()[0].avg_cost, 100.0);
+        let pnl = p.realized_pnl();
+        assert_eq!(pnl.len(), 1);
+        assert_eq!(pnl[0].currency, "USD");
+        assert_eq!(pnl[0].amount, 80.0);
+    }
+
+    #[test]
+    fn closes_a_position() {
+        let mut p = Portfolio::new();
+        p.apply(buy("US0000000001", 10.0, 100.0));
+        p.apply(sell("US0000000001", 10.0, 90.0));
+        assert!(p.positions().is_empty());
+        let pnl = p.realized_pnl();
+        assert_eq!(pnl[0].amount, -100.0);
+    }
+
+    #[test]
+    fn flipping_a_position_resets_avg_cost_for_the_new_side() {
+        let mut p = Portfolio::new();
+        p.apply(buy("US0000000001", 10.0, 100.0));
+        p.apply(sell("US0000000001", 15.0, 90.0));
+        let pos = &p.positions()[0];
+        assert_eq!(pos.quantity, -5.0);
+        assert_eq!(pos.avg_cost, 90.0);
+        let pnl = p.realized_pnl();
+        assert_eq!(pnl[0].amount, -100.0);
+    }
+
+    #[test]
+    fn notional_and_unrealized_pnl_sum_multiple_positions() {
+        let mut p = Portfolio::new();
+        p.apply(buy("US0000000001", 10.0, 100.0));
+        p.apply(buy("US0000000002", 5.0, 50.0));
+        let quotes = TestQuotes::new(&[("US0000000001", 110.0, "USD"), ("US0000000002", 40.0, "USD")]);
+
+        let notional = p.notional(&quotes).unwrap();
+        assert_eq!(notional.currency, "USD");
+        assert_eq!(notional.amount, 10.0 * 110.0 + 5.0 * 40.0);
+
+        let unrealized = p.unrealized_pnl(&quotes).unwrap();
+        assert_eq!(unrealized.len(), 1);
+        assert_eq!(unrealized[0].currency, "USD");
+        // (110-100)*10 + (40-50)*5
+        assert_eq!(unrealized[0].amount, 50.0);
+    }
+
+    #[test]
+    fn notional_rejects_a_multi_currency_portfolio() {
+        let mut p = Portfolio::new();
+        p.apply(buy_in("US0000000001", "USD", 10.0, 100.0));
+        p.apply(buy_in("GB0000000002", "GBP", 5.0, 50.0));
+        let quotes = TestQuotes::new(&[("US0000000001", 110.0, "USD"), ("GB0000000002", 45.0, "GBP")]);
+
+        assert_eq!(
+            p.notional(&quotes),
+            Err(RequestError::BadResponse(
+                "portfolio spans more than one currency".to_string()
+            ))
+        );
+    }
+
+    #[test]
+    fn closed_position_is_excluded_from_notional_and_does_not_need_a_quote() {
+        let mut p = Portfolio::new();
+        // Fully close out a GBP position...
+        p.apply(buy_in("GB0000000001", "GBP", 10.0, 100.0));
+        p.apply(sell_in("GB0000000001", "GBP", 10.0, 90.0));
+        // ...then open a USD one.
+        p.apply(buy_in("US0000000002", "USD", 5.0, 50.0));
+
+        // The quote double only knows about the still-open USD position, so
+        // this would fail with NotFound if the closed GBP leg were still
+        // iterated.
+        let quotes = TestQuotes::new(&[("US0000000002", 60.0, "USD")]);
+
+        let notional = p.notional(&quotes).unwrap();
+        assert_eq!(notional.currency, "USD");
+        assert_eq!(notional.amount, 5.0 * 60.0);
+    }
+
+    #[test]
+    fn reopening_a_closed_position_starts_a_fresh_avg_cost() {
+        let mut p = Portfolio::new();
+        p.apply(buy("US0000000001", 10.0, 100.0));
+        p.apply(sell("US0000000001", 10.0, 90.0));
+        assert!(p.positions().is_empty());
+
+        p.apply(buy("US0000000001", 4.0, 70.0));
+        assert_eq!(p.positions().len(), 1);
+        assert_eq!(p.positions()[0].quantity, 4.0);
+        assert_eq!(p.positions()[0].avg_cost, 70.0);
+    }
+}
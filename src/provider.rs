@@ -0,0 +1,87 @@
+//! Provider-neutral request traits.
+//!
+//! This module defines the building blocks that let a binary crate fetch live
+//! [Market] and [Company] data without knowing which backend produced it. A
+//! [Provider] might be a web scraper, a local CSV file, or an exchange API;
+//! callers only depend on the request traits defined herein, never on the
+//! concrete backend behind them.
+
+use std::fmt;
+
+use crate::{Company, Market};
+
+/// The error returned by a [Provider] and the request traits built on top of it.
+///
+/// # Description
+///
+/// `RequestError` gathers the failure modes that are common to any remote or
+/// local data source: the backend does not know how to answer the request,
+/// the caller has been throttled, the requested entity does not exist, or the
+/// transport/response itself failed.
+#[derive(Debug, Clone, PartialEq)]
+pub enum RequestError {
+    /// The provider does not implement the requested operation.
+    NotSupported,
+    /// The caller exceeded the provider's rate limit.
+    RateLimited,
+    /// The requested entity was not found by the provider.
+    NotFound,
+    /// The underlying transport (network, file, ...) failed.
+    Transport(String),
+    /// The provider responded, but the response could not be interpreted.
+    BadResponse(String),
+}
+
+impl fmt::Display for RequestError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            RequestError::NotSupported => write!(f, "operation not supported by this provider"),
+            RequestError::RateLimited => write!(f, "rate limited by this provider"),
+            RequestError::NotFound => write!(f, "entity not found"),
+            RequestError::Transport(msg) => write!(f, "transport error: {msg}"),
+            RequestError::BadResponse(msg) => write!(f, "bad response: {msg}"),
+        }
+    }
+}
+
+impl std::error::Error for RequestError {}
+
+/// The result type returned by every request trait in this crate.
+pub type RequestResult<T> = Result<T, RequestError>;
+
+/// A backend that can answer requests on behalf of `Market`/`Company` objects.
+///
+/// # Description
+///
+/// `Provider` is an identity trait: it does not fetch anything by itself, but
+/// gives a name to the backend (a web scraper, a local CSV, an exchange API,
+/// ...) so callers can tell which data source answered a request. Actual
+/// fetching is expressed through request traits such as [FetchMarket] and
+/// [FetchCompany], which a backend implements alongside `Provider`.
+pub trait Provider {
+    /// Get the name that identifies this provider, e.g. `"nasdaq-scraper"`.
+    fn provider_name(&self) -> &str;
+}
+
+/// A [Provider] capable of fetching [Market] objects.
+pub trait FetchMarket: Provider {
+    /// Get the [Market] identified by `name`.
+    ///
+    /// # Description
+    ///
+    /// This method asks the provider to build a fresh [Market] object for the
+    /// market whose name is equal to `name`, from whatever backend is
+    /// implementing this trait.
+    fn market(&self, name: &str) -> RequestResult<Box<dyn Market>>;
+}
+
+/// A [Provider] capable of fetching [Company] objects.
+pub trait FetchCompany: Provider {
+    /// Get the [Company] whose ticker is equal to `ticker`.
+    ///
+    /// # Description
+    ///
+    /// An exhaustive match is applied between `ticker` and the ticker of the
+    /// fetched company, mirroring [Market::stock_by_ticker].
+    fn company_by_ticker(&self, ticker: &str) -> RequestResult<Box<dyn Company>>;
+}
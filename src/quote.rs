@@ -0,0 +1,91 @@
+//! Price quotes and OHLC history.
+//!
+//! `Market`/`Company` enumerate instruments but say nothing about their
+//! price; this module adds the [Quote]/[Ohlc] data objects and the
+//! [FetchQuote] request trait that a [Provider](crate::provider::Provider)
+//! implements to actually retrieve them.
+
+use crate::provider::{Provider, RequestResult};
+use crate::Company;
+
+/// A point-in-time price quote for a [Company].
+///
+/// # Description
+///
+/// `currency` is the ISO 4217 code the quote is denominated in, mirroring
+/// [Market::currency](crate::Market::currency). It is carried on `Quote`
+/// itself (rather than looked up from the market) so a quote stays
+/// self-describing even when fetched without a `Market` reference at hand.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Quote {
+    /// The last traded price.
+    pub price: f64,
+    /// The best bid price.
+    pub bid: f64,
+    /// The best ask price.
+    pub ask: f64,
+    /// The traded volume.
+    pub volume: f64,
+    /// The ISO 4217 currency code this quote is denominated in.
+    pub currency: String,
+    /// The UTC timestamp of this quote, in RFC 3339 format.
+    pub timestamp: String,
+}
+
+/// An open/high/low/close/volume bar over an [Interval].
+#[derive(Debug, Clone, PartialEq)]
+pub struct Ohlc {
+    /// The opening price of the bar.
+    pub open: f64,
+    /// The highest price traded during the bar.
+    pub high: f64,
+    /// The lowest price traded during the bar.
+    pub low: f64,
+    /// The closing price of the bar.
+    pub close: f64,
+    /// The traded volume during the bar.
+    pub volume: f64,
+    /// The [Interval] this bar spans.
+    pub interval: Interval,
+}
+
+/// The duration spanned by a single [Ohlc] bar.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Interval {
+    /// One minute.
+    OneMinute,
+    /// Five minutes.
+    FiveMinutes,
+    /// Fifteen minutes.
+    FifteenMinutes,
+    /// One hour.
+    OneHour,
+    /// One day.
+    OneDay,
+    /// One week.
+    OneWeek,
+    /// One month.
+    OneMonth,
+}
+
+/// A UTC date range, bounding a [FetchQuote::history] request.
+///
+/// # Description
+///
+/// Both bounds are RFC 3339 UTC timestamps and inclusive.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DateRange {
+    /// The start of the range (inclusive).
+    pub from: String,
+    /// The end of the range (inclusive).
+    pub to: String,
+}
+
+/// A [Provider] capable of fetching [Quote]s and [Ohlc] history for a [Company].
+pub trait FetchQuote: Provider {
+    /// Get the latest [Quote] for `c`.
+    fn latest_quote(&self, c: &dyn Company) -> RequestResult<Quote>;
+
+    /// Get the [Ohlc] history for `c` over `range`, bucketed by `interval`.
+    fn history(&self, c: &dyn Company, interval: Interval, range: DateRange) -> RequestResult<Vec<Ohlc>>;
+}